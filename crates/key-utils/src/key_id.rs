@@ -0,0 +1,229 @@
+//! Deterministic key identifiers, for indexing and lookup.
+//!
+//! A [`KeyId`] is the lowercase-hex SHA-256 digest of a canonical,
+//! curve-tagged serialization of a public key, following the approach
+//! [TUF](https://theupdateframework.io/) uses to identify keys. Tagging the
+//! hash input with the curve means the same physical key always hashes to
+//! the same `KeyId` regardless of which wrapper type produced it, and gives
+//! the crate a stable map key for storing per-participant verifying keys or
+//! for log/telemetry correlation without leaking the full key.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+/// Length in bytes of a [`KeyId`] (a SHA-256 digest).
+pub const KEY_ID_LENGTH: usize = 32;
+
+const ED25519_TAG: &[u8] = b"ed25519";
+const SECP256K1_TAG: &[u8] = b"secp256k1";
+
+/// Error type for parsing a [`KeyId`] from hex.
+#[derive(Debug, Error)]
+pub enum KeyIdError {
+    #[error("Invalid KeyId hex encoding: {0}")]
+    InvalidHex(#[from] hex::FromHexError),
+
+    #[error("Invalid KeyId length: expected {expected}, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+}
+
+/// A stable identifier for a public key: the SHA-256 digest of the curve
+/// tag followed by the raw public key bytes.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct KeyId([u8; KEY_ID_LENGTH]);
+
+impl KeyId {
+    fn from_canonical_bytes(curve_tag: &[u8], public_key_bytes: &[u8]) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(curve_tag);
+        hasher.update(public_key_bytes);
+
+        let mut bytes = [0u8; KEY_ID_LENGTH];
+        bytes.copy_from_slice(&hasher.finalize());
+        Self(bytes)
+    }
+
+    /// Computes the `KeyId` of an ed25519 verifying key.
+    pub fn from_verifying_key(key: &ed25519_dalek::VerifyingKey) -> Self {
+        Self::from_canonical_bytes(ED25519_TAG, key.as_bytes())
+    }
+
+    /// Computes the `KeyId` of a secp256k1 verifying key, using its SEC1
+    /// compressed point encoding as the canonical public key bytes.
+    pub fn from_secp256k1_verifying_key(key: &k256::ecdsa::VerifyingKey) -> Self {
+        Self::from_canonical_bytes(SECP256K1_TAG, key.to_sec1_bytes().as_ref())
+    }
+
+    /// Computes the `KeyId` of a FROST ed25519 group verifying key. Uses the
+    /// same compressed-point encoding as [`KeyId::from_verifying_key`], so a
+    /// FROST group key and the `ed25519_dalek::VerifyingKey` it corresponds
+    /// to hash to the same `KeyId`.
+    pub fn from_frost_ed25519_verifying_key(
+        key: &threshold_signatures::frost_ed25519::VerifyingKey,
+    ) -> Result<Self, crate::PublicKeyConversionError> {
+        let bytes = key
+            .serialize()
+            .map_err(|e| crate::PublicKeyConversionError::SerializationFailed(e.to_string()))?;
+
+        Ok(Self::from_canonical_bytes(ED25519_TAG, &bytes))
+    }
+
+    /// Computes the `KeyId` of a FROST secp256k1 group verifying key. Uses
+    /// the same SEC1 compressed-point encoding as
+    /// [`KeyId::from_secp256k1_verifying_key`], so a FROST group key and the
+    /// `k256::ecdsa::VerifyingKey` it corresponds to hash to the same
+    /// `KeyId`.
+    pub fn from_frost_secp256k1_verifying_key(
+        key: &threshold_signatures::frost_secp256k1::VerifyingKey,
+    ) -> Self {
+        use k256::elliptic_curve::sec1::ToEncodedPoint;
+
+        let bytes = key.to_element().to_encoded_point(true).to_bytes();
+        Self::from_canonical_bytes(SECP256K1_TAG, &bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; KEY_ID_LENGTH] {
+        &self.0
+    }
+}
+
+impl fmt::Display for KeyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(self.0))
+    }
+}
+
+impl fmt::Debug for KeyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "KeyId({self})")
+    }
+}
+
+impl FromStr for KeyId {
+    type Err = KeyIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = hex::decode(s)?;
+        let array: [u8; KEY_ID_LENGTH] =
+            bytes
+                .try_into()
+                .map_err(|v: Vec<u8>| KeyIdError::InvalidLength {
+                    expected: KEY_ID_LENGTH,
+                    actual: v.len(),
+                })?;
+
+        Ok(Self(array))
+    }
+}
+
+impl Serialize for KeyId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for KeyId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(|e: KeyIdError| serde::de::Error::custom(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::SigningKey;
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn test_key_id_is_deterministic() {
+        let key = SigningKey::generate(&mut OsRng).verifying_key();
+        assert_eq!(KeyId::from_verifying_key(&key), KeyId::from_verifying_key(&key));
+    }
+
+    #[test]
+    fn test_key_id_differs_per_key() {
+        let a = SigningKey::generate(&mut OsRng).verifying_key();
+        let b = SigningKey::generate(&mut OsRng).verifying_key();
+        assert_ne!(KeyId::from_verifying_key(&a), KeyId::from_verifying_key(&b));
+    }
+
+    #[test]
+    fn test_key_id_display_from_str_roundtrip() {
+        let key = SigningKey::generate(&mut OsRng).verifying_key();
+        let id = KeyId::from_verifying_key(&key);
+
+        let parsed: KeyId = id.to_string().parse().unwrap();
+        assert_eq!(id, parsed);
+    }
+
+    #[test]
+    fn test_key_id_serde_roundtrip() {
+        let key = SigningKey::generate(&mut OsRng).verifying_key();
+        let id = KeyId::from_verifying_key(&key);
+
+        let json = serde_json::to_string(&id).unwrap();
+        let parsed: KeyId = serde_json::from_str(&json).unwrap();
+        assert_eq!(id, parsed);
+    }
+
+    #[test]
+    fn test_key_id_rejects_invalid_length() {
+        let result: Result<KeyId, _> = "abcd".parse();
+        assert!(matches!(result, Err(KeyIdError::InvalidLength { .. })));
+    }
+
+    #[test]
+    fn test_ed25519_and_secp256k1_keys_do_not_collide() {
+        use k256::ecdsa::SigningKey as Secp256k1SigningKey;
+
+        let ed25519_key = SigningKey::generate(&mut OsRng).verifying_key();
+        let secp256k1_key = *Secp256k1SigningKey::random(&mut OsRng).verifying_key();
+
+        // Curve tagging means this would only collide on a SHA-256 break,
+        // not merely because the raw bytes happen to overlap.
+        assert_ne!(
+            KeyId::from_verifying_key(&ed25519_key).to_string(),
+            KeyId::from_secp256k1_verifying_key(&secp256k1_key).to_string()
+        );
+    }
+
+    #[test]
+    fn test_frost_ed25519_key_id_matches_dalek_counterpart() {
+        use threshold_signatures::frost_ed25519;
+
+        let dalek_key = SigningKey::generate(&mut OsRng).verifying_key();
+        let frost_key = frost_ed25519::VerifyingKey::deserialize(&dalek_key.to_bytes()).unwrap();
+
+        let dalek_id = KeyId::from_verifying_key(&dalek_key);
+        let frost_id = KeyId::from_frost_ed25519_verifying_key(&frost_key).unwrap();
+
+        assert_eq!(dalek_id, frost_id);
+    }
+
+    #[test]
+    fn test_frost_secp256k1_key_id_matches_k256_counterpart() {
+        use k256::ecdsa::SigningKey as Secp256k1SigningKey;
+        use threshold_signatures::frost_secp256k1;
+
+        let secp256k1_key = Secp256k1SigningKey::random(&mut OsRng);
+        let k256_verifying = *secp256k1_key.verifying_key();
+        let affine = *k256_verifying.as_affine();
+        let frost_key = frost_secp256k1::VerifyingKey::new(affine.into());
+
+        let k256_id = KeyId::from_secp256k1_verifying_key(&k256_verifying);
+        let frost_id = KeyId::from_frost_secp256k1_verifying_key(&frost_key);
+
+        assert_eq!(k256_id, frost_id);
+    }
+}
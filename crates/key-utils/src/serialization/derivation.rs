@@ -0,0 +1,231 @@
+//! SLIP-0010 hierarchical deterministic derivation for ed25519 signing keys.
+//!
+//! ed25519 has no public-key (non-hardened) derivation: [`derive_key`]
+//! hardens every index it is given, and [`DerivationPath`] only parses
+//! components carrying the hardened marker, so `m/44'/397'/0'` is accepted
+//! and `m/44/397/0` is rejected. See
+//! <https://github.com/satoshilabs/slips/blob/master/slip-0010.md>.
+
+use std::fmt;
+use std::str::FromStr;
+
+use ed25519_dalek::SigningKey;
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+
+use super::KeyError;
+
+type HmacSha512 = Hmac<Sha512>;
+
+/// The SLIP-0010 seed key for the ed25519 curve.
+const ED25519_SEED_KEY: &[u8] = b"ed25519 seed";
+
+/// Bit set on a derivation index to mark it hardened, per BIP-32.
+const HARDENED_BIT: u32 = 0x8000_0000;
+
+/// A derivation node (master or child): a 32-byte key and a 32-byte chain code.
+struct Node {
+    key: [u8; 32],
+    chain_code: [u8; 32],
+}
+
+fn master_node(seed: &[u8]) -> Node {
+    let mut mac = HmacSha512::new_from_slice(ED25519_SEED_KEY).expect("HMAC accepts any key length");
+    mac.update(seed);
+    let i = mac.finalize().into_bytes();
+
+    let mut key = [0u8; 32];
+    let mut chain_code = [0u8; 32];
+    key.copy_from_slice(&i[..32]);
+    chain_code.copy_from_slice(&i[32..]);
+
+    Node { key, chain_code }
+}
+
+fn derive_child(parent: &Node, index_hardened: u32) -> Node {
+    let mut mac = HmacSha512::new_from_slice(&parent.chain_code).expect("HMAC accepts any key length");
+    mac.update(&[0x00]);
+    mac.update(&parent.key);
+    mac.update(&index_hardened.to_be_bytes());
+    let i = mac.finalize().into_bytes();
+
+    let mut key = [0u8; 32];
+    let mut chain_code = [0u8; 32];
+    key.copy_from_slice(&i[..32]);
+    chain_code.copy_from_slice(&i[32..]);
+
+    Node { key, chain_code }
+}
+
+/// Derive a child [`SigningKey`] from a master `seed` along `path`, a list
+/// of child indices. Each index is OR'd with the hardened bit internally,
+/// since ed25519 only supports hardened derivation.
+pub fn derive_key(seed: &[u8], path: &[u32]) -> SigningKey {
+    let mut node = master_node(seed);
+
+    for &index in path {
+        node = derive_child(&node, index | HARDENED_BIT);
+    }
+
+    SigningKey::from_bytes(&node.key)
+}
+
+/// A BIP32-style derivation path, e.g. `m/44'/397'/0'`.
+///
+/// ed25519 only supports hardened derivation, so every component must carry
+/// the `'`/`h` hardened marker; a component without it is rejected with
+/// [`KeyError::NonHardenedIndex`], as is a component whose raw value is
+/// already `>= 2^31`, since such a path cannot be re-expressed losslessly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerivationPath(Vec<u32>);
+
+impl DerivationPath {
+    pub fn as_indices(&self) -> &[u32] {
+        &self.0
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "m")?;
+        for index in &self.0 {
+            write!(f, "/{index}'")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = KeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = |reason: &str| KeyError::InvalidDerivationPath(s.to_string(), reason.to_string());
+
+        let rest = s.strip_prefix("m/").or_else(|| s.strip_prefix("m")).ok_or_else(|| {
+            err("path must start with 'm' or 'm/'")
+        })?;
+        let rest = rest.strip_prefix('/').unwrap_or(rest);
+
+        if rest.is_empty() {
+            return Ok(DerivationPath(Vec::new()));
+        }
+
+        rest.split('/')
+            .map(|component| {
+                let (digits, hardened) = match component.strip_suffix('\'').or_else(|| component.strip_suffix('h')) {
+                    Some(digits) => (digits, true),
+                    None => (component, false),
+                };
+
+                let index: u32 = digits.parse().map_err(|_| err("path components must be integers"))?;
+
+                if !hardened || index & HARDENED_BIT != 0 {
+                    return Err(KeyError::NonHardenedIndex(index));
+                }
+
+                Ok(index)
+            })
+            .collect::<Result<Vec<u32>, KeyError>>()
+            .map(DerivationPath)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vector from SLIP-0010 (seed = 000102030405060708090a0b0c0d0e0f).
+    const TEST_SEED: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f,
+    ];
+
+    #[test]
+    fn test_master_node_is_deterministic() {
+        let a = master_node(&TEST_SEED);
+        let b = master_node(&TEST_SEED);
+        assert_eq!(a.key, b.key);
+        assert_eq!(a.chain_code, b.chain_code);
+    }
+
+    fn hex32(s: &str) -> [u8; 32] {
+        hex::decode(s).unwrap().try_into().unwrap()
+    }
+
+    // SLIP-0010 ed25519 test vector 1, master node for
+    // seed = 000102030405060708090a0b0c0d0e0f.
+    // https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+    #[test]
+    fn test_master_node_matches_slip0010_test_vector() {
+        let node = master_node(&TEST_SEED);
+        assert_eq!(
+            node.key,
+            hex32("2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7")
+        );
+        assert_eq!(
+            node.chain_code,
+            hex32("90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb")
+        );
+    }
+
+    // SLIP-0010 ed25519 test vector 1, chain m/0' for
+    // seed = 000102030405060708090a0b0c0d0e0f.
+    // https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+    #[test]
+    fn test_derive_child_matches_slip0010_test_vector() {
+        let master = master_node(&TEST_SEED);
+        let child = derive_child(&master, 0 | HARDENED_BIT);
+
+        assert_eq!(
+            child.key,
+            hex32("68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3")
+        );
+        assert_eq!(
+            child.chain_code,
+            hex32("8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69")
+        );
+    }
+
+    #[test]
+    fn test_derive_key_is_deterministic() {
+        let a = derive_key(&TEST_SEED, &[44, 397, 0]);
+        let b = derive_key(&TEST_SEED, &[44, 397, 0]);
+        assert_eq!(a.to_bytes(), b.to_bytes());
+    }
+
+    #[test]
+    fn test_derive_key_differs_per_path() {
+        let a = derive_key(&TEST_SEED, &[44, 397, 0]);
+        let b = derive_key(&TEST_SEED, &[44, 397, 1]);
+        assert_ne!(a.to_bytes(), b.to_bytes());
+    }
+
+    #[test]
+    fn test_parse_path() {
+        let path: DerivationPath = "m/44'/397'/0'".parse().unwrap();
+        assert_eq!(path.as_indices(), &[44, 397, 0]);
+
+        let path: DerivationPath = "m/44h/397h/0h".parse().unwrap();
+        assert_eq!(path.as_indices(), &[44, 397, 0]);
+    }
+
+    #[test]
+    fn test_parse_path_rejects_missing_hardened_marker() {
+        let result: Result<DerivationPath, _> = "m/44/397/0".parse();
+        assert!(matches!(result, Err(KeyError::NonHardenedIndex(_))));
+    }
+
+    #[test]
+    fn test_parse_path_rejects_hardened_value() {
+        let result: Result<DerivationPath, _> = "m/2147483648'".parse();
+        assert!(matches!(result, Err(KeyError::NonHardenedIndex(_))));
+    }
+
+    #[test]
+    fn test_derive_key_from_parsed_path() {
+        let path: DerivationPath = "m/44'/397'/0'".parse().unwrap();
+        let key = derive_key(&TEST_SEED, path.as_indices());
+        let expected = derive_key(&TEST_SEED, &[44, 397, 0]);
+        assert_eq!(key.to_bytes(), expected.to_bytes());
+    }
+}
@@ -0,0 +1,399 @@
+use ed25519_dalek::SigningKey;
+use k256::ecdsa::SigningKey as Secp256k1SigningKey;
+use thiserror::Error;
+use zeroize::Zeroizing;
+
+pub mod derivation;
+pub mod storage;
+
+/// Constants for key serialization
+const ED25519_PREFIX: &str = "ed25519:";
+const ED25519_KEY_LENGTH: usize = 32;
+const SECP256K1_PREFIX: &str = "secp256k1:";
+const SECP256K1_KEY_LENGTH: usize = 32;
+
+/// Error type for key parsing
+#[derive(Debug, Error)]
+pub enum KeyError {
+    #[error("Key must start with '{ED25519_PREFIX}'")]
+    MissingPrefix,
+
+    #[error("Invalid base58 encoding: {0}")]
+    InvalidBase58(#[from] bs58::decode::Error),
+
+    #[error("Invalid key length: expected {expected}, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("Unrecognized key curve prefix: '{0}'")]
+    UnknownCurve(String),
+
+    #[error("Invalid secp256k1 key: {0}")]
+    InvalidSecp256k1(String),
+
+    #[error("Invalid derivation path '{0}': {1}")]
+    InvalidDerivationPath(String, String),
+
+    #[error("Non-hardened derivation index {0} is not supported for ed25519 (SLIP-0010 only permits hardened indices)")]
+    NonHardenedIndex(u32),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid key JSON: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+}
+
+/// The curve of a [`SigningKeyKind`], used to pick the `curve:base58` prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Curve {
+    Ed25519,
+    Secp256k1,
+}
+
+impl Curve {
+    fn prefix(self) -> &'static str {
+        match self {
+            Curve::Ed25519 => ED25519_PREFIX,
+            Curve::Secp256k1 => SECP256K1_PREFIX,
+        }
+    }
+}
+
+/// A signing key tagged with the curve it belongs to, following NEAR's
+/// `curve:base58` convention (see `near_sdk::CurveType`/`PublicKey` and
+/// `near-crypto`'s `KeyType`) so mixed-curve keysets keep their curve
+/// information on round-trip.
+#[derive(Clone)]
+pub enum SigningKeyKind {
+    Ed25519(SigningKey),
+    Secp256k1(Secp256k1SigningKey),
+}
+
+impl SigningKeyKind {
+    pub fn curve(&self) -> Curve {
+        match self {
+            SigningKeyKind::Ed25519(_) => Curve::Ed25519,
+            SigningKeyKind::Secp256k1(_) => Curve::Secp256k1,
+        }
+    }
+}
+
+/// An encoded `curve:base58` key string that zeroizes its contents on drop,
+/// so the secret doesn't linger in memory once it's been decoded.
+#[derive(Clone)]
+pub struct SecretString(Zeroizing<String>);
+
+impl SecretString {
+    pub fn new(s: String) -> Self {
+        Self(Zeroizing::new(s))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(s: String) -> Self {
+        Self::new(s)
+    }
+}
+
+/// Encode a signing key with the ed25519: prefix
+pub fn encode_key(key: &SigningKey) -> String {
+    format!(
+        "{}{}",
+        ED25519_PREFIX,
+        bs58::encode(key.to_bytes()).into_string()
+    )
+}
+
+/// Decode a signing key from a string with ed25519: prefix
+pub fn decode_key(s: &str) -> Result<SigningKey, KeyError> {
+    let key_str = s
+        .strip_prefix(ED25519_PREFIX)
+        .ok_or(KeyError::MissingPrefix)?;
+
+    decode_ed25519_bytes(key_str)
+}
+
+/// Decode the base58 body of an `ed25519:` key, scrubbing the decoded bytes
+/// once the `SigningKey` has been constructed from them.
+fn decode_ed25519_bytes(key_str: &str) -> Result<SigningKey, KeyError> {
+    let key_bytes: Zeroizing<Vec<u8>> = Zeroizing::new(
+        bs58::decode(key_str)
+            .into_vec()
+            .map_err(KeyError::InvalidBase58)?,
+    );
+
+    if key_bytes.len() != ED25519_KEY_LENGTH {
+        return Err(KeyError::InvalidLength {
+            expected: ED25519_KEY_LENGTH,
+            actual: key_bytes.len(),
+        });
+    }
+
+    let mut key_array: Zeroizing<[u8; ED25519_KEY_LENGTH]> =
+        Zeroizing::new([0u8; ED25519_KEY_LENGTH]);
+    key_array.copy_from_slice(&key_bytes);
+
+    Ok(SigningKey::from_bytes(&key_array))
+}
+
+/// Decode the base58 body of a `secp256k1:` key, scrubbing the decoded bytes
+/// once the `SigningKey` has been constructed from them.
+fn decode_secp256k1_bytes(key_str: &str) -> Result<Secp256k1SigningKey, KeyError> {
+    let key_bytes: Zeroizing<Vec<u8>> = Zeroizing::new(
+        bs58::decode(key_str)
+            .into_vec()
+            .map_err(KeyError::InvalidBase58)?,
+    );
+
+    if key_bytes.len() != SECP256K1_KEY_LENGTH {
+        return Err(KeyError::InvalidLength {
+            expected: SECP256K1_KEY_LENGTH,
+            actual: key_bytes.len(),
+        });
+    }
+
+    Secp256k1SigningKey::from_slice(&key_bytes).map_err(|e| KeyError::InvalidSecp256k1(e.to_string()))
+}
+
+/// Encode a curve-tagged signing key as a `curve:base58` string.
+pub fn encode_any_key(key: &SigningKeyKind) -> String {
+    match key {
+        SigningKeyKind::Ed25519(key) => encode_key(key),
+        SigningKeyKind::Secp256k1(key) => format!(
+            "{}{}",
+            Curve::Secp256k1.prefix(),
+            bs58::encode(key.to_bytes()).into_string()
+        ),
+    }
+}
+
+/// Decode a curve-tagged signing key from a `curve:base58` string, dispatching
+/// on the prefix (`ed25519:` or `secp256k1:`).
+pub fn decode_any_key(s: &str) -> Result<SigningKeyKind, KeyError> {
+    if let Some(key_str) = s.strip_prefix(ED25519_PREFIX) {
+        return decode_ed25519_bytes(key_str).map(SigningKeyKind::Ed25519);
+    }
+
+    if let Some(key_str) = s.strip_prefix(SECP256K1_PREFIX) {
+        return decode_secp256k1_bytes(key_str).map(SigningKeyKind::Secp256k1);
+    }
+
+    let prefix = s.split_once(':').map_or(s, |(prefix, _)| prefix);
+    Err(KeyError::UnknownCurve(prefix.to_string()))
+}
+
+/// Serde module for single ed25519 keys
+pub mod ed25519_key {
+    use super::{SecretString, decode_key, encode_key};
+    use ed25519_dalek::SigningKey;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(key: &SigningKey, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&encode_key(key))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<SigningKey, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = SecretString::new(String::deserialize(deserializer)?);
+        decode_key(s.as_str()).map_err(|e| serde::de::Error::custom(e.to_string()))
+    }
+}
+
+/// Serde module for vectors of ed25519 keys
+pub mod ed25519_key_vec {
+    use super::{decode_key, encode_key};
+    use ed25519_dalek::SigningKey;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(keys: &[SigningKey], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let encoded_keys: Vec<String> = keys.iter().map(encode_key).collect();
+        encoded_keys.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<SigningKey>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let strings = Vec::<String>::deserialize(deserializer)?;
+
+        strings
+            .into_iter()
+            .map(|s| decode_key(&s).map_err(|e| serde::de::Error::custom(e.to_string())))
+            .collect()
+    }
+}
+
+/// Serde module for a single curve-tagged signing key (`SigningKeyKind`),
+/// letting config structs hold either ed25519 or secp256k1 keys without
+/// losing curve information on round-trip.
+pub mod any_key {
+    use super::{SigningKeyKind, decode_any_key, encode_any_key};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(key: &SigningKeyKind, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&encode_any_key(key))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<SigningKeyKind, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        decode_any_key(&s).map_err(|e| serde::de::Error::custom(e.to_string()))
+    }
+}
+
+/// Serde module for vectors of curve-tagged signing keys.
+pub mod any_key_vec {
+    use super::{SigningKeyKind, decode_any_key, encode_any_key};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(keys: &[SigningKeyKind], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let encoded_keys: Vec<String> = keys.iter().map(encode_any_key).collect();
+        encoded_keys.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<SigningKeyKind>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let strings = Vec::<String>::deserialize(deserializer)?;
+
+        strings
+            .into_iter()
+            .map(|s| decode_any_key(&s).map_err(|e| serde::de::Error::custom(e.to_string())))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use ed25519_dalek::SigningKey;
+    use rand::rngs::OsRng;
+    use serde::{Deserialize, Serialize};
+
+    use crate::serialization::{KeyError, decode_key};
+
+    #[test]
+    fn test_key_encode_decode_roundtrip() {
+        use super::{decode_key, encode_key};
+
+        let key = SigningKey::generate(&mut OsRng);
+        let encoded = encode_key(&key);
+        let decoded = decode_key(&encoded).unwrap();
+
+        assert_eq!(key.to_bytes(), decoded.to_bytes());
+    }
+
+    #[test]
+    fn test_key_serialize_deserialize_roundtrip() {
+        use super::ed25519_key;
+
+        let key = SigningKey::generate(&mut OsRng);
+
+        #[derive(Clone, Serialize, Deserialize)]
+        struct KeyWrapper {
+            #[serde(with = "ed25519_key")]
+            key: SigningKey,
+        }
+
+        let key_wrapper = KeyWrapper { key };
+        let key_wrapper_clone = key_wrapper.clone();
+
+        let serialized = serde_json::to_string(&key_wrapper).unwrap();
+        let deserialized: KeyWrapper = serde_json::from_str(&serialized).unwrap();
+
+        // Verify the keys are identical
+        assert_eq!(
+            key_wrapper_clone.key.to_bytes(),
+            deserialized.key.to_bytes()
+        );
+    }
+
+    #[test]
+    fn test_invalid_prefix() {
+        let result = decode_key("invalid:key");
+        assert_matches!(result, Err(KeyError::MissingPrefix));
+    }
+
+    #[test]
+    fn test_invalid_base58() {
+        let result = decode_key("ed25519:invalid!base58");
+        assert_matches!(result, Err(KeyError::InvalidBase58(_)));
+    }
+
+    #[test]
+    fn test_valid_base58() {
+        let result = decode_key("ed25519:DXkVZkHd7WUUejCK7i74uAoZWy1w9AZqshhTHxhmqHuB");
+        assert_matches!(result, Ok(_));
+    }
+
+    #[test]
+    fn test_any_key_ed25519_roundtrip() {
+        use super::{SigningKeyKind, decode_any_key, encode_any_key};
+
+        let key = SigningKey::generate(&mut OsRng);
+        let encoded = encode_any_key(&SigningKeyKind::Ed25519(key.clone()));
+        assert!(encoded.starts_with("ed25519:"));
+
+        match decode_any_key(&encoded).unwrap() {
+            SigningKeyKind::Ed25519(decoded) => assert_eq!(key.to_bytes(), decoded.to_bytes()),
+            SigningKeyKind::Secp256k1(_) => panic!("expected ed25519 key"),
+        }
+    }
+
+    #[test]
+    fn test_any_key_secp256k1_roundtrip() {
+        use super::{SigningKeyKind, decode_any_key, encode_any_key};
+        use k256::ecdsa::SigningKey as Secp256k1SigningKey;
+
+        let key = Secp256k1SigningKey::random(&mut OsRng);
+        let encoded = encode_any_key(&SigningKeyKind::Secp256k1(key.clone()));
+        assert!(encoded.starts_with("secp256k1:"));
+
+        match decode_any_key(&encoded).unwrap() {
+            SigningKeyKind::Secp256k1(decoded) => {
+                assert_eq!(key.to_bytes(), decoded.to_bytes())
+            }
+            SigningKeyKind::Ed25519(_) => panic!("expected secp256k1 key"),
+        }
+    }
+
+    #[test]
+    fn test_any_key_unknown_curve() {
+        use super::decode_any_key;
+
+        let result = decode_any_key("bls12381:abcdef");
+        assert_matches!(result, Err(KeyError::UnknownCurve(prefix)) if prefix == "bls12381");
+    }
+
+    #[test]
+    fn test_secret_string_roundtrip_through_decode_key() {
+        use super::SecretString;
+
+        let key = SigningKey::generate(&mut OsRng);
+        let secret = SecretString::new(super::encode_key(&key));
+
+        let decoded = decode_key(secret.as_str()).unwrap();
+        assert_eq!(key.to_bytes(), decoded.to_bytes());
+    }
+}
@@ -0,0 +1,264 @@
+//! Keypair file load/store helpers, in the spirit of Solana's
+//! `read_keypair_file`/`write_keypair_file`: write atomically (temp file in
+//! the same directory, then rename) and, on unix, with `0o600` permissions
+//! so secret keys are never world-readable.
+
+use std::fs;
+use std::path::Path;
+
+use ed25519_dalek::SigningKey;
+use serde::{Deserialize, Serialize};
+
+use super::{KeyError, decode_key, encode_key};
+
+/// On-disk encoding for a signing key file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyFileFormat {
+    /// The `ed25519:`-prefixed base58 string used elsewhere in this crate.
+    Bs58,
+    /// A raw JSON byte array `[u8; 64]` of `pubkey || secret`, for interop
+    /// with existing tooling.
+    Json,
+}
+
+impl KeyFileFormat {
+    /// Picks a format based on the file extension, defaulting to [`Bs58`]
+    /// for anything other than `.json`.
+    ///
+    /// [`Bs58`]: KeyFileFormat::Bs58
+    fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => KeyFileFormat::Json,
+            _ => KeyFileFormat::Bs58,
+        }
+    }
+}
+
+/// The raw JSON representation of a signing key: `pubkey || secret`.
+#[derive(Serialize, Deserialize)]
+struct JsonKeyBytes(Vec<u8>);
+
+fn encode_for_format(key: &SigningKey, format: KeyFileFormat) -> Result<Vec<u8>, KeyError> {
+    match format {
+        KeyFileFormat::Bs58 => Ok(encode_key(key).into_bytes()),
+        KeyFileFormat::Json => {
+            let mut bytes = Vec::with_capacity(64);
+            bytes.extend_from_slice(key.verifying_key().as_bytes());
+            bytes.extend_from_slice(&key.to_bytes());
+            Ok(serde_json::to_vec(&JsonKeyBytes(bytes))?)
+        }
+    }
+}
+
+fn decode_for_format(contents: &[u8], format: KeyFileFormat) -> Result<SigningKey, KeyError> {
+    match format {
+        KeyFileFormat::Bs58 => decode_key(std::str::from_utf8(contents).unwrap_or_default()),
+        KeyFileFormat::Json => {
+            let JsonKeyBytes(bytes) = serde_json::from_slice(contents)?;
+            if bytes.len() != 64 {
+                return Err(KeyError::InvalidLength {
+                    expected: 64,
+                    actual: bytes.len(),
+                });
+            }
+
+            let secret: [u8; 32] = bytes[32..].try_into().expect("length checked above");
+            Ok(SigningKey::from_bytes(&secret))
+        }
+    }
+}
+
+/// Writes `contents` to `path` atomically: the data is written to a
+/// uniquely-named temp file created in the same directory via
+/// [`tempfile::NamedTempFile`] (which opens with `O_EXCL`, so a pre-existing
+/// file or symlink at that path is never followed), tightened to `0o600`
+/// permissions on unix, then persisted (renamed) over `path`.
+fn write_atomic(path: &Path, contents: &[u8]) -> Result<(), KeyError> {
+    use std::io::Write;
+
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let mut tmp_file = tempfile::NamedTempFile::new_in(dir)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        tmp_file
+            .as_file()
+            .set_permissions(fs::Permissions::from_mode(0o600))?;
+    }
+
+    tmp_file.write_all(contents)?;
+    tmp_file.as_file().sync_all()?;
+    tmp_file
+        .persist(path)
+        .map_err(|persist_error| KeyError::Io(persist_error.error))?;
+
+    Ok(())
+}
+
+/// Persists a signing key to `path`, picking [`KeyFileFormat::Bs58`] or
+/// [`KeyFileFormat::Json`] based on the file extension (`.json` selects the
+/// JSON format).
+pub fn write_signing_key<P: AsRef<Path>>(key: &SigningKey, path: P) -> Result<(), KeyError> {
+    let path = path.as_ref();
+    let format = KeyFileFormat::from_path(path);
+    let contents = encode_for_format(key, format)?;
+    write_atomic(path, &contents)
+}
+
+/// Loads a signing key from `path`, picking the format based on the file
+/// extension (`.json` selects the JSON format).
+pub fn read_signing_key<P: AsRef<Path>>(path: P) -> Result<SigningKey, KeyError> {
+    let path = path.as_ref();
+    let format = KeyFileFormat::from_path(path);
+    let contents = fs::read(path)?;
+    decode_for_format(&contents, format)
+}
+
+/// Persists a vector of signing keys to `path` as a JSON array of 64-byte
+/// `pubkey || secret` arrays (`.json` extension) or newline-separated
+/// `ed25519:` strings otherwise.
+pub fn write_signing_key_vec<P: AsRef<Path>>(keys: &[SigningKey], path: P) -> Result<(), KeyError> {
+    let path = path.as_ref();
+    let format = KeyFileFormat::from_path(path);
+
+    let contents = match format {
+        KeyFileFormat::Bs58 => keys
+            .iter()
+            .map(encode_key)
+            .collect::<Vec<_>>()
+            .join("\n")
+            .into_bytes(),
+        KeyFileFormat::Json => {
+            let encoded: Vec<Vec<u8>> = keys
+                .iter()
+                .map(|key| {
+                    let mut bytes = Vec::with_capacity(64);
+                    bytes.extend_from_slice(key.verifying_key().as_bytes());
+                    bytes.extend_from_slice(&key.to_bytes());
+                    bytes
+                })
+                .collect();
+            serde_json::to_vec(&encoded)?
+        }
+    };
+
+    write_atomic(path, &contents)
+}
+
+/// Loads a vector of signing keys from `path`, in whichever format
+/// [`write_signing_key_vec`] used to write it.
+pub fn read_signing_key_vec<P: AsRef<Path>>(path: P) -> Result<Vec<SigningKey>, KeyError> {
+    let path = path.as_ref();
+    let format = KeyFileFormat::from_path(path);
+    let contents = fs::read(path)?;
+
+    match format {
+        KeyFileFormat::Bs58 => std::str::from_utf8(&contents)
+            .unwrap_or_default()
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(decode_key)
+            .collect(),
+        KeyFileFormat::Json => {
+            let encoded: Vec<Vec<u8>> = serde_json::from_slice(&contents)?;
+            encoded
+                .into_iter()
+                .map(|bytes| {
+                    if bytes.len() != 64 {
+                        return Err(KeyError::InvalidLength {
+                            expected: 64,
+                            actual: bytes.len(),
+                        });
+                    }
+
+                    let secret: [u8; 32] = bytes[32..].try_into().expect("length checked above");
+                    Ok(SigningKey::from_bytes(&secret))
+                })
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn test_write_read_bs58_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("key-utils-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("key.bs58");
+
+        let key = SigningKey::generate(&mut OsRng);
+        write_signing_key(&key, &path).unwrap();
+        let loaded = read_signing_key(&path).unwrap();
+
+        assert_eq!(key.to_bytes(), loaded.to_bytes());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+            assert_eq!(mode, 0o600);
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_read_json_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("key-utils-test-json-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("key.json");
+
+        let key = SigningKey::generate(&mut OsRng);
+        write_signing_key(&key, &path).unwrap();
+        let loaded = read_signing_key(&path).unwrap();
+
+        assert_eq!(key.to_bytes(), loaded.to_bytes());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_read_vec_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("key-utils-test-vec-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("keys.json");
+
+        let keys = vec![
+            SigningKey::generate(&mut OsRng),
+            SigningKey::generate(&mut OsRng),
+        ];
+        write_signing_key_vec(&keys, &path).unwrap();
+        let loaded = read_signing_key_vec(&path).unwrap();
+
+        assert_eq!(keys.len(), loaded.len());
+        for (key, loaded) in keys.iter().zip(loaded.iter()) {
+            assert_eq!(key.to_bytes(), loaded.to_bytes());
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_read_truncated_json_keyfile_errors_instead_of_panicking() {
+        let dir = std::env::temp_dir().join(format!("key-utils-test-trunc-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("key.json");
+
+        fs::write(&path, serde_json::to_vec(&JsonKeyBytes(vec![1, 2, 3])).unwrap()).unwrap();
+
+        let result = read_signing_key(&path);
+        assert!(matches!(result, Err(KeyError::InvalidLength { .. })));
+
+        let vec_path = dir.join("keys.json");
+        fs::write(&vec_path, serde_json::to_vec(&vec![vec![1u8, 2, 3]]).unwrap()).unwrap();
+        let result = read_signing_key_vec(&vec_path);
+        assert!(matches!(result, Err(KeyError::InvalidLength { .. })));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}